@@ -28,6 +28,7 @@ use std::ops::Deref;
 use std::hash::{Hash, Hasher};
 use std::cmp::Ordering;
 use std::borrow::Borrow;
+use std::mem;
 use shared::Shared;
 
 use self::ConsListNode::{Cons, Nil};
@@ -457,6 +458,33 @@ impl<A> ConsList<A> {
     pub fn ptr_eq(&self, other: &Self) -> bool {
         Arc::ptr_eq(&self.0, &other.0)
     }
+
+    /// Call a function with each element at the front of the list
+    /// whose cell isn't shared with any other list, stopping at the
+    /// first cell which is.
+    ///
+    /// Time: O(n) in the number of unshared cells
+    pub(crate) fn for_each_unshared<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Arc<A>),
+    {
+        let mut node = &mut self.0;
+        loop {
+            if let Nil = **node {
+                return;
+            }
+            if Arc::strong_count(node) > 1 {
+                return;
+            }
+            match Arc::get_mut(node) {
+                Some(&mut Cons(_, ref mut a, ref mut tail)) => {
+                    f(a);
+                    node = &mut tail.0;
+                }
+                _ => return,
+            }
+        }
+    }
 }
 
 impl ConsList<i32> {
@@ -565,6 +593,42 @@ impl<A> Clone for ConsList<A> {
     }
 }
 
+impl<A> Drop for ConsList<A> {
+    /// Drop a list.
+    ///
+    /// Cells which aren't shared with any other list are unlinked
+    /// one at a time, so that dropping a long list doesn't recurse
+    /// once for every cell in it. Unlinked cells are all given the
+    /// same empty tail, so this doesn't allocate per cell.
+    fn drop(&mut self) {
+        let mut empty = None;
+        let mut next = unlink_tail(&mut self.0, &mut empty);
+        while let Some(mut node) = next {
+            next = unlink_tail(&mut node, &mut empty);
+        }
+    }
+}
+
+fn unlink_tail<A>(
+    node: &mut Arc<ConsListNode<A>>,
+    empty: &mut Option<Arc<ConsListNode<A>>>,
+) -> Option<Arc<ConsListNode<A>>> {
+    match **node {
+        Cons(_, _, ref tail) if !tail.is_empty() && Arc::strong_count(&tail.0) == 1 => (),
+        _ => return None,
+    }
+    if Arc::strong_count(node) > 1 {
+        return None;
+    }
+    match Arc::get_mut(node) {
+        Some(&mut Cons(_, _, ref mut tail)) => {
+            let empty = empty.get_or_insert_with(|| Arc::new(Nil)).clone();
+            Some(mem::replace(&mut tail.0, empty))
+        }
+        _ => None,
+    }
+}
+
 impl<A> Default for ConsList<A> {
     /// `Default` for lists is the empty list.
     fn default() -> Self {
@@ -849,6 +913,13 @@ mod test {
         assert_eq!(l1, l2);
     }
 
+    #[test]
+    fn drop_long_list() {
+        let l = ConsList::range(0, 1_000_000);
+        assert_eq!(1_000_001, l.len());
+        drop(l);
+    }
+
     quickcheck! {
         fn length(vec: Vec<i32>) -> bool {
             let list = ConsList::from(vec.clone());
@@ -23,6 +23,9 @@ use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 use std::fmt::{Debug, Error, Formatter};
 use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::mem;
+use conslist::ConsList;
 use queue::Queue;
 use shared::Shared;
 
@@ -184,6 +187,41 @@ impl<A> List<A> {
         it.into_iter().map(|a| a.shared()).collect()
     }
 
+    /// Construct a list by calling a function repeatedly until it
+    /// returns `None`.
+    ///
+    /// Each value the function returns becomes the next element of
+    /// the list, and the function is called exactly once per element
+    /// plus once for the final `None`.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use im::list::List;
+    /// # fn main() {
+    /// let mut count = 0;
+    /// let list = List::from_fn(|| {
+    ///     count += 1;
+    ///     if count <= 3 { Some(count) } else { None }
+    /// });
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn from_fn<R, F>(mut f: F) -> List<A>
+    where
+        F: FnMut() -> Option<R>,
+        R: Shared<A>,
+    {
+        let mut out = List::new();
+        while let Some(a) = f() {
+            out = out.snoc(a);
+        }
+        out
+    }
+
     /// Test whether a list is empty.
     pub fn is_empty(&self) -> bool {
         match *self.0 {
@@ -224,6 +262,31 @@ impl<A> List<A> {
         }
     }
 
+    /// Get the element at a given index in a list.
+    ///
+    /// If the index is out of bounds, `None` is returned.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// let list = list![1, 2, 3];
+    /// assert_eq!(Some(Arc::new(2)), list.get(1));
+    /// assert_eq!(None, list.get(3));
+    /// # }
+    /// ```
+    pub fn get(&self, index: usize) -> Option<Arc<A>> {
+        if index >= self.len() {
+            None
+        } else {
+            self.iter().nth(index)
+        }
+    }
+
     /// Get the last element of a list, as well as the list with the last
     /// element removed.
     ///
@@ -250,9 +313,24 @@ impl<A> List<A> {
 
     /// Get the last element of a list.
     ///
+    /// Unlike [`pop_back`][pop_back], this doesn't construct the rest
+    /// of the list, and it doesn't recurse.
+    ///
     /// If the list is empty, `None` is returned.
+    ///
+    /// [pop_back]: #method.pop_back
     pub fn last(&self) -> Option<Arc<A>> {
-        self.pop_back().map(|(a, _)| a)
+        let mut list = self.clone();
+        loop {
+            let next = match *list.0 {
+                Nil => return None,
+                Cons(_, ref a, ref q) => match q.pop_back() {
+                    None => return Some(a.clone()),
+                    Some((last_list, _)) => last_list,
+                },
+            };
+            list = next.deref().clone();
+        }
     }
 
     /// Get the list without the last element.
@@ -301,12 +379,39 @@ impl<A> List<A> {
         }
     }
 
+    /// Construct a list which is the current list repeated `times`
+    /// times.
+    ///
+    /// Every repetition shares its structure with the current list.
+    ///
+    /// Time: O(times)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2].cycle_n(3),
+    ///   list![1, 2, 1, 2, 1, 2]
+    /// );
+    /// # }
+    /// ```
+    pub fn cycle_n(&self, times: usize) -> Self {
+        let mut out = List::new();
+        for _ in 0..times {
+            out = out.append(self);
+        }
+        out
+    }
+
     fn link<R>(&self, other: R) -> Self
     where
         R: Borrow<Self>,
     {
         match *self.0 {
             Nil => other.borrow().clone(),
+            Cons(_, _, _) if other.borrow().is_empty() => self.clone(),
             Cons(l, ref a, ref q) => List(Arc::new(Cons(
                 l + other.borrow().len(),
                 a.clone(),
@@ -334,6 +439,33 @@ impl<A> List<A> {
         self.cons(a)
     }
 
+    /// Construct a list with a new value prepended to the front of the
+    /// current list if `cond` is true, or the current list unchanged
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// let verbose = false;
+    /// assert_eq!(
+    ///   list!["file"].cons_if(verbose, "-v").cons_if(true, "ls"),
+    ///   list!["ls", "file"]
+    /// );
+    /// # }
+    /// ```
+    pub fn cons_if<R>(&self, cond: bool, a: R) -> Self
+    where
+        R: Shared<A>,
+    {
+        if cond {
+            self.cons(a)
+        } else {
+            self.clone()
+        }
+    }
+
     /// Construct a list with a new value appended to the back of the
     /// current list.
     ///
@@ -412,6 +544,41 @@ impl<A> List<A> {
             .and_then(|(a1, d)| d.uncons().map(|(a2, d)| (a1, a2, d)))
     }
 
+    /// Get up to `n` elements from the front of a list, as well as the
+    /// rest of the list.
+    ///
+    /// If the list has fewer than `n` elements, all of them are
+    /// returned along with an empty list.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use im::list::List;
+    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// let (head, rest) = List::range(0, 9).uncons_n(3);
+    /// assert_eq!(vec![Arc::new(0), Arc::new(1), Arc::new(2)], head);
+    /// assert_eq!(List::range(3, 9), rest);
+    /// # }
+    /// ```
+    pub fn uncons_n(&self, n: usize) -> (Vec<Arc<A>>, List<A>) {
+        let mut head = Vec::with_capacity(::std::cmp::min(n, self.len()));
+        let mut rest = self.clone();
+        while head.len() < n {
+            match rest.pop_front() {
+                None => break,
+                Some((a, d)) => {
+                    head.push(a);
+                    rest = d;
+                }
+            }
+        }
+        (head, rest)
+    }
+
     /// Get an iterator over a list.
     #[inline]
     pub fn iter(&self) -> Iter<A> {
@@ -420,7 +587,1143 @@ impl<A> List<A> {
         }
     }
 
-    /// Construct a list which is the reverse of the current list.
+    /// Construct a list which is the reverse of the current list.
+    ///
+    /// This walks the whole list, accumulating its elements in a
+    /// [`ConsList`][conslist::ConsList] rather than by consing onto a
+    /// list, which would nest every element one level deeper than the
+    /// last.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use im::list::List;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4, 5].reverse(),
+    ///   list![5, 4, 3, 2, 1]
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [conslist::ConsList]: ../conslist/struct.ConsList.html
+    pub fn reverse(&self) -> Self {
+        let mut out = ConsList::new();
+        for i in self.iter() {
+            out = out.cons(i)
+        }
+        out.iter().collect()
+    }
+
+    /// Construct a list with only the first `n` elements of the
+    /// current list.
+    ///
+    /// If the list is shorter than `n`, it's returned unchanged.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4, 5].take(3),
+    ///   list![1, 2, 3]
+    /// );
+    /// # }
+    /// ```
+    pub fn take(&self, n: usize) -> Self {
+        if n >= self.len() {
+            self.clone()
+        } else {
+            self.iter().take(n).collect()
+        }
+    }
+
+    /// Construct a list with the longest prefix of the current list
+    /// whose elements all satisfy a predicate.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use im::list::List;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   List::range(1, 1000).take_while(|a| *a < 5),
+    ///   list![1, 2, 3, 4]
+    /// );
+    /// # }
+    /// ```
+    pub fn take_while<F>(&self, f: F) -> Self
+    where
+        F: Fn(&A) -> bool,
+    {
+        self.take(self.iter().take_while(|a| f(a)).count())
+    }
+
+    /// Construct a list with the first `n` elements of the current
+    /// list removed.
+    ///
+    /// The remainder of the list is shared with the current list.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4, 5].drop(3),
+    ///   list![4, 5]
+    /// );
+    /// # }
+    /// ```
+    pub fn drop(&self, n: usize) -> Self {
+        if n >= self.len() {
+            return List::new();
+        }
+        let mut out = self.clone();
+        for _ in 0..n {
+            match out.tail() {
+                None => break,
+                Some(d) => out = d,
+            }
+        }
+        out
+    }
+
+    /// Split a list into its first `n` elements and the rest, in a
+    /// single walk over the prefix.
+    ///
+    /// This is equivalent to `(list.take(n), list.drop(n))`. The
+    /// remainder of the list is shared with the current list.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4, 5].take_drop(3),
+    ///   (list![1, 2, 3], list![4, 5])
+    /// );
+    /// # }
+    /// ```
+    pub fn take_drop(&self, n: usize) -> (Self, Self) {
+        if n >= self.len() {
+            return (self.clone(), List::new());
+        }
+        let mut iter = self.iter();
+        let prefix = iter.by_ref().take(n).collect();
+        (prefix, iter.current)
+    }
+
+    /// Construct a list with the longest prefix of the current list
+    /// whose elements all satisfy a predicate removed.
+    ///
+    /// The remainder of the list is shared with the current list.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4, 1].drop_while(|a| *a < 3),
+    ///   list![3, 4, 1]
+    /// );
+    /// # }
+    /// ```
+    pub fn drop_while<F>(&self, f: F) -> Self
+    where
+        F: Fn(&A) -> bool,
+    {
+        self.drop(self.iter().take_while(|a| f(a)).count())
+    }
+
+    /// Construct a list with the longest suffix of the current list
+    /// whose elements all satisfy a predicate removed.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 0, 2, 0, 0].drop_while_end(|a| *a == 0),
+    ///   list![1, 0, 2]
+    /// );
+    /// # }
+    /// ```
+    pub fn drop_while_end<F>(&self, f: F) -> Self
+    where
+        F: Fn(&A) -> bool,
+    {
+        let keep = self.iter()
+            .enumerate()
+            .fold(0, |keep, (i, a)| if f(&a) { keep } else { i + 1 });
+        self.take(keep)
+    }
+
+    /// Split a list into sublists wherever an element equal to `delim`
+    /// occurs, dropping the delimiters.
+    ///
+    /// Like [`str::split`][str::split], consecutive delimiters produce
+    /// empty sublists between them, a delimiter at either end produces
+    /// an empty sublist at that end, and an empty list produces a
+    /// single empty sublist.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 0, 2, 3, 0, 4].split_on(&0),
+    ///   list![list![1], list![2, 3], list![4]]
+    /// );
+    ///
+    /// assert_eq!(
+    ///   list![1, 0, 0, 2, 0].split_on(&0),
+    ///   list![list![1], list![], list![2], list![]]
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [str::split]: https://doc.rust-lang.org/std/primitive.str.html#method.split
+    pub fn split_on(&self, delim: &A) -> List<List<A>>
+    where
+        A: PartialEq,
+    {
+        let mut out = List::new();
+        let mut current = List::new();
+        for a in self.iter() {
+            if a.deref() == delim {
+                out = out.snoc(current);
+                current = List::new();
+            } else {
+                current = current.snoc(a);
+            }
+        }
+        out.snoc(current)
+    }
+
+    /// Split a list into sublists of exactly `n` elements each.
+    ///
+    /// If the length of the list isn't a multiple of `n`, the
+    /// remaining elements at the end are left out.
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4, 5, 6, 7].chunks_exact(3),
+    ///   list![list![1, 2, 3], list![4, 5, 6]]
+    /// );
+    /// # }
+    /// ```
+    pub fn chunks_exact(&self, n: usize) -> List<List<A>> {
+        assert!(n != 0, "List::chunks_exact: chunk size must be non-zero");
+        let mut out = List::new();
+        let mut rest = self.clone();
+        while rest.len() >= n {
+            let (chunk, next) = rest.take_drop(n);
+            out = out.snoc(chunk);
+            rest = next;
+        }
+        out
+    }
+
+    /// Group runs of adjacent elements which share the same key.
+    ///
+    /// Each group is returned along with the key its elements share.
+    /// Elements with the same key which aren't adjacent end up in
+    /// separate groups.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// let log = list![("info", 1), ("info", 2), ("warn", 3), ("info", 4)];
+    /// assert_eq!(
+    ///   log.group_by_key(|entry| entry.0),
+    ///   list![
+    ///     ("info", list![("info", 1), ("info", 2)]),
+    ///     ("warn", list![("warn", 3)]),
+    ///     ("info", list![("info", 4)])
+    ///   ]
+    /// );
+    /// # }
+    /// ```
+    pub fn group_by_key<K, F>(&self, key: F) -> List<(K, List<A>)>
+    where
+        K: PartialEq,
+        F: Fn(&A) -> K,
+    {
+        let mut out = List::new();
+        let mut current: Option<(K, List<A>)> = None;
+        for a in self.iter() {
+            let k = key(&a);
+            current = match current {
+                Some((ref group_key, ref group)) if *group_key == k => Some((k, group.snoc(a))),
+                Some(group) => {
+                    out = out.snoc(group);
+                    Some((k, List::singleton(a)))
+                }
+                None => Some((k, List::singleton(a))),
+            };
+        }
+        match current {
+            None => out,
+            Some(group) => out.snoc(group),
+        }
+    }
+
+    /// Construct a list padded at the end with copies of `fill` until
+    /// it's at least `len` elements long.
+    ///
+    /// If the list is already at least `len` elements long, it's
+    /// returned unchanged. All the padding elements share the same
+    /// [`Arc`][std::sync::Arc].
+    ///
+    /// Time: O(len)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2].pad_to(5, 0),
+    ///   list![1, 2, 0, 0, 0]
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [std::sync::Arc]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    pub fn pad_to<R>(&self, len: usize, fill: R) -> Self
+    where
+        R: Shared<A>,
+    {
+        let fill = fill.shared();
+        let mut out = self.clone();
+        while out.len() < len {
+            out = out.snoc(fill.clone());
+        }
+        out
+    }
+
+    /// Construct a list with the first `n` elements of the current
+    /// list moved to the end.
+    ///
+    /// If `n` is larger than the length of the list, it wraps around.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4, 5].rotate_left(2),
+    ///   list![3, 4, 5, 1, 2]
+    /// );
+    /// # }
+    /// ```
+    pub fn rotate_left(&self, n: usize) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let mut front = List::new();
+        let mut back = self.clone();
+        for _ in 0..(n % self.len()) {
+            match back.pop_front() {
+                None => break,
+                Some((a, d)) => {
+                    front = front.snoc(a);
+                    back = d;
+                }
+            }
+        }
+        back.append(front)
+    }
+
+    /// Construct a list with the last `n` elements of the current
+    /// list moved to the front.
+    ///
+    /// If `n` is larger than the length of the list, it wraps around.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4, 5].rotate_right(2),
+    ///   list![4, 5, 1, 2, 3]
+    /// );
+    /// # }
+    /// ```
+    pub fn rotate_right(&self, n: usize) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let len = self.len();
+        self.rotate_left(len - n % len)
+    }
+
+    /// Construct a list of each element of the current list paired
+    /// with its index.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list!["a", "b", "c"].enumerate(),
+    ///   list![(0, Arc::new("a")), (1, Arc::new("b")), (2, Arc::new("c"))]
+    /// );
+    /// # }
+    /// ```
+    pub fn enumerate(&self) -> List<(usize, Arc<A>)> {
+        self.iter().enumerate().collect()
+    }
+
+    /// Construct a list of each pair of adjacent elements in the
+    /// current list.
+    ///
+    /// A list with fewer than two elements produces an empty list.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3, 4].pairwise(),
+    ///   list![
+    ///     (Arc::new(1), Arc::new(2)),
+    ///     (Arc::new(2), Arc::new(3)),
+    ///     (Arc::new(3), Arc::new(4))
+    ///   ]
+    /// );
+    /// # }
+    /// ```
+    pub fn pairwise(&self) -> List<(Arc<A>, Arc<A>)> {
+        self.iter().zip(self.iter().skip(1)).collect()
+    }
+
+    /// Construct a list with a value computed from each pair of adjacent
+    /// elements inserted between them.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 4, 9].interpose_with(|a, b| b - a),
+    ///   list![1, 3, 4, 5, 9]
+    /// );
+    /// # }
+    /// ```
+    pub fn interpose_with<F>(&self, f: F) -> Self
+    where
+        F: Fn(&A, &A) -> A,
+    {
+        let mut out = List::new();
+        let mut prev: Option<Arc<A>> = None;
+        for a in self.iter() {
+            if let Some(ref p) = prev {
+                out = out.snoc(f(p, &a));
+            }
+            out = out.snoc(a.clone());
+            prev = Some(a);
+        }
+        out
+    }
+
+    /// Combine three lists element by element into a list of triples.
+    ///
+    /// The resulting list is as long as the shortest of the three.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// let zipped = list![1, 2, 3].zip3(list!["a", "b"], list![true, false, true]);
+    /// assert_eq!(
+    ///   zipped,
+    ///   list![
+    ///     (Arc::new(1), Arc::new("a"), Arc::new(true)),
+    ///     (Arc::new(2), Arc::new("b"), Arc::new(false))
+    ///   ]
+    /// );
+    /// # }
+    /// ```
+    pub fn zip3<B, C, RB, RC>(&self, b: RB, c: RC) -> List<(Arc<A>, Arc<B>, Arc<C>)>
+    where
+        RB: Borrow<List<B>>,
+        RC: Borrow<List<C>>,
+    {
+        self.zip_with3(b, c, |a, b, c| (a, b, c))
+    }
+
+    /// Combine three lists element by element using a function.
+    ///
+    /// The resulting list is as long as the shortest of the three.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 3].zip_with3(list![10, 20, 30], list![100, 200], |a, b, c| *a + *b + *c),
+    ///   list![111, 222]
+    /// );
+    /// # }
+    /// ```
+    pub fn zip_with3<B, C, D, RB, RC, F>(&self, b: RB, c: RC, f: F) -> List<D>
+    where
+        RB: Borrow<List<B>>,
+        RC: Borrow<List<C>>,
+        F: Fn(Arc<A>, Arc<B>, Arc<C>) -> D,
+    {
+        self.iter()
+            .zip(b.borrow().iter())
+            .zip(c.borrow().iter())
+            .map(|((a, b), c)| f(a, b, c))
+            .collect()
+    }
+
+    /// Test whether two lists are equal, looking at no more than
+    /// `limit` elements.
+    ///
+    /// Returns `false` if a difference between the two lists is found
+    /// within the first `limit` elements, where one list running out
+    /// before the other counts as a difference, and `true` otherwise.
+    /// A list which ends exactly at the bound isn't different from a
+    /// longer one, because the point where they differ lies past the
+    /// bound. This is the same rule [`compare_bounded`][compare_bounded]
+    /// uses, so `eq_bounded` returns `false` exactly where it returns
+    /// an ordering other than `Equal`.
+    ///
+    /// Time: O(limit)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use im::list::List;
+    /// # fn main() {
+    /// let naturals = List::range(0, 2000);
+    /// let diverging = List::range(0, 1500).push_back(0);
+    /// assert!(naturals.eq_bounded(&diverging, 1000));
+    /// assert!(!naturals.eq_bounded(&diverging, 1600));
+    /// assert!(!list![1, 2].eq_bounded(list![1, 2, 3], 1000));
+    /// assert!(list![1, 2].eq_bounded(list![1, 2, 3], 2));
+    /// # }
+    /// ```
+    ///
+    /// [compare_bounded]: #method.compare_bounded
+    pub fn eq_bounded<R>(&self, other: R, limit: usize) -> bool
+    where
+        A: PartialEq,
+        R: Borrow<Self>,
+    {
+        let mut left = self.iter();
+        let mut right = other.borrow().iter();
+        for _ in 0..limit {
+            match (left.next(), right.next()) {
+                (None, None) => return true,
+                (Some(a), Some(b)) => {
+                    if a != b {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Compare two lists, looking at no more than `limit` elements.
+    ///
+    /// Returns the ordering of the two lists if a difference between
+    /// them is found within the first `limit` elements, where one
+    /// list running out before the other counts as a difference, or
+    /// `Equal` if both lists end within that bound. Otherwise, or if
+    /// two elements can't be compared, returns `None`.
+    /// A list which ends exactly at the bound isn't different from a
+    /// longer one, because the point where they differ lies past the
+    /// bound.
+    ///
+    /// Time: O(limit)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use im::list::List;
+    /// # use std::cmp::Ordering;
+    /// # fn main() {
+    /// let naturals = List::range(0, 2000);
+    /// assert_eq!(None, naturals.compare_bounded(&naturals, 1000));
+    ///
+    /// let different = List::range(0, 4).push_back(100).append(List::range(6, 2000));
+    /// assert_eq!(
+    ///   Some(Ordering::Less),
+    ///   naturals.compare_bounded(&different, 1000)
+    /// );
+    ///
+    /// assert_eq!(None, list![1, 2].compare_bounded(list![1, 2, 3], 2));
+    /// # }
+    /// ```
+    pub fn compare_bounded<R>(&self, other: R, limit: usize) -> Option<Ordering>
+    where
+        A: PartialOrd,
+        R: Borrow<Self>,
+    {
+        let mut left = self.iter();
+        let mut right = other.borrow().iter();
+        for _ in 0..limit {
+            match (left.next(), right.next()) {
+                (None, None) => return Some(Ordering::Equal),
+                (None, Some(_)) => return Some(Ordering::Less),
+                (Some(_), None) => return Some(Ordering::Greater),
+                (Some(a), Some(b)) => match a.partial_cmp(&b) {
+                    Some(Ordering::Equal) => (),
+                    ord => return ord,
+                },
+            }
+        }
+        match (left.len(), right.len()) {
+            (0, 0) => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+
+    /// Sort a list using a comparator function.
+    ///
+    /// The sort is stable: elements which compare equal keep their
+    /// relative order. The elements are collected into a vector,
+    /// sorted there and built back into a list, so this doesn't
+    /// recurse.
+    ///
+    /// Time: O(n log n)
+    pub fn sort_by<F>(&self, cmp: F) -> Self
+    where
+        F: Fn(Arc<A>, Arc<A>) -> Ordering,
+    {
+        let mut items: Vec<Arc<A>> = self.iter().collect();
+        items.sort_by(|a, b| cmp(a.clone(), b.clone()));
+        items.into_iter().collect()
+    }
+
+    /// Sort a list by a key extracted from each element.
+    ///
+    /// The sort is stable: elements with equal keys keep their
+    /// relative order.
+    ///
+    /// Time: O(n log n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![(2, "a"), (1, "b"), (2, "c"), (1, "d")].sort_by_key(|p| p.0),
+    ///   list![(1, "b"), (1, "d"), (2, "a"), (2, "c")]
+    /// );
+    /// # }
+    /// ```
+    pub fn sort_by_key<K, F>(&self, f: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&A) -> K,
+    {
+        self.sort_by(|a, b| f(&a).cmp(&f(&b)))
+    }
+
+    /// Insert an item into a list sorted using a comparator function.
+    ///
+    /// Constructs a new list with the new item inserted before the
+    /// first item in the list which is larger than the new item,
+    /// as determined by the comparator.
+    ///
+    /// Only the elements before the insertion point are copied; the
+    /// rest of the list is shared with the current list.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![6, 4, 2].insert_by(5, |a, b| b.cmp(&a)),
+    ///   list![6, 5, 4, 2]
+    /// );
+    /// # }
+    /// ```
+    pub fn insert_by<T, F>(&self, item: T, cmp: F) -> Self
+    where
+        T: Shared<A>,
+        F: Fn(Arc<A>, Arc<A>) -> Ordering,
+    {
+        let item = item.shared();
+        let split = self.iter()
+            .take_while(|a| cmp(a.clone(), item.clone()) != Ordering::Greater)
+            .count();
+        let (prefix, suffix) = self.take_drop(split);
+        prefix.snoc(item).append(suffix)
+    }
+
+    /// Merge two lists sorted using a comparator function into a
+    /// single sorted list.
+    ///
+    /// When elements from both lists compare equal, the one from the
+    /// current list comes first. Once either list runs out, the rest
+    /// of the other is shared with the result.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![5, 3, 1].merge_by(list![6, 4, 2, 0], |a, b| b.cmp(&a)),
+    ///   list![6, 5, 4, 3, 2, 1, 0]
+    /// );
+    /// # }
+    /// ```
+    pub fn merge_by<R, F>(&self, other: R, cmp: F) -> Self
+    where
+        R: Borrow<Self>,
+        F: Fn(Arc<A>, Arc<A>) -> Ordering,
+    {
+        let mut left = self.clone();
+        let mut right = other.borrow().clone();
+        let mut out = List::new();
+        loop {
+            match (left.head(), right.head()) {
+                (None, _) => return out.append(right),
+                (_, None) => return out.append(left),
+                (Some(a), Some(b)) => {
+                    if cmp(a.clone(), b.clone()) == Ordering::Greater {
+                        out = out.snoc(b);
+                        right = right.tail().unwrap_or_default();
+                    } else {
+                        out = out.snoc(a);
+                        left = left.tail().unwrap_or_default();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Test whether a list is sorted in ascending order.
+    ///
+    /// Stops at the first pair of elements found to be out of order.
+    /// Elements which can't be compared, such as a `NaN` next to any
+    /// other float, count as out of order.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert!(list![1, 2, 2, 3].is_sorted());
+    /// assert!(!list![1, 3, 2].is_sorted());
+    /// # }
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        A: PartialOrd,
+    {
+        let mut it = self.iter();
+        match it.next() {
+            None => true,
+            Some(mut prev) => {
+                for a in it {
+                    match prev.partial_cmp(&a) {
+                        Some(Ordering::Greater) | None => return false,
+                        _ => prev = a,
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    /// Construct a list from the elements of the current list for
+    /// which a function returns a value, keeping those values.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list!["1", "two", "3", "four"].filter_map(|s| s.parse::<i32>().ok()),
+    ///   list![1, 3]
+    /// );
+    /// # }
+    /// ```
+    pub fn filter_map<B, F>(&self, f: F) -> List<B>
+    where
+        F: Fn(&A) -> Option<B>,
+    {
+        self.iter().filter_map(|a| f(&a)).collect()
+    }
+
+    /// Remove consecutive duplicates from a list, as determined by
+    /// a function.
+    ///
+    /// Each element is compared with the last element kept, and
+    /// skipped if the function returns `true`. As with
+    /// [`Vec::dedup_by`][Vec::dedup_by], the function is given the
+    /// current element first and the last element kept second. The
+    /// first element is always kept.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 4, 5, 9, 10].dedup_by(|a, b| a - b == 1),
+    ///   list![1, 4, 9]
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [Vec::dedup_by]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup_by
+    pub fn dedup_by<F>(&self, f: F) -> Self
+    where
+        F: Fn(&A, &A) -> bool,
+    {
+        let mut last: Option<Arc<A>> = None;
+        self.iter()
+            .filter(|a| {
+                let keep = match last {
+                    Some(ref prev) => !f(a, prev),
+                    None => true,
+                };
+                if keep {
+                    last = Some(a.clone());
+                }
+                keep
+            })
+            .collect()
+    }
+
+    /// Remove consecutive duplicates from a list.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 1, 2, 2, 2, 1, 3, 3].dedup(),
+    ///   list![1, 2, 1, 3]
+    /// );
+    /// # }
+    /// ```
+    pub fn dedup(&self) -> Self
+    where
+        A: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Test whether any element of a list satisfies a predicate.
+    ///
+    /// Stops at the first element which does.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert!(list![1, 2, 3].any(|a| *a > 2));
+    /// assert!(!list![1, 2, 3].any(|a| *a > 3));
+    /// # }
+    /// ```
+    pub fn any<F>(&self, f: F) -> bool
+    where
+        F: Fn(&A) -> bool,
+    {
+        self.iter().any(|a| f(&a))
+    }
+
+    /// Test whether every element of a list satisfies a predicate.
+    ///
+    /// Stops at the first element which doesn't.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert!(list![1, 2, 3].all(|a| *a > 0));
+    /// assert!(!list![1, 2, 3].all(|a| *a > 1));
+    /// # }
+    /// ```
+    pub fn all<F>(&self, f: F) -> bool
+    where
+        F: Fn(&A) -> bool,
+    {
+        self.iter().all(|a| f(&a))
+    }
+
+    /// Find the first element of a list which satisfies a predicate.
+    ///
+    /// If no element satisfies it, `None` is returned.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   Some(Arc::new(4)),
+    ///   list![1, 3, 4, 5, 6].find(|a| a % 2 == 0)
+    /// );
+    /// # }
+    /// ```
+    pub fn find<F>(&self, f: F) -> Option<Arc<A>>
+    where
+        F: Fn(&A) -> bool,
+    {
+        self.iter().find(|a| f(a))
+    }
+
+    /// Find the index of the first element of a list which satisfies a
+    /// predicate.
+    ///
+    /// If no element satisfies it, `None` is returned.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   Some(2),
+    ///   list![1, 3, 4, 5, 6].position(|a| a % 2 == 0)
+    /// );
+    /// # }
+    /// ```
+    pub fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: Fn(&A) -> bool,
+    {
+        self.iter().position(|a| f(&a))
+    }
+
+    /// Reduce a list from the left, using its first element as the
+    /// initial accumulator.
+    ///
+    /// If the list is empty, `None` is returned.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   Some(Arc::new(-8)),
+    ///   list![1, 2, 3, 4].fold_left1(|a, b| *a - *b)
+    /// );
+    /// # }
+    /// ```
+    pub fn fold_left1<F, R>(&self, f: F) -> Option<Arc<A>>
+    where
+        F: Fn(Arc<A>, Arc<A>) -> R,
+        R: Shared<A>,
+    {
+        let mut it = self.iter();
+        it.next().map(|first| it.fold(first, |acc, a| f(acc, a).shared()))
+    }
+
+    /// Reduce a list from the right, using its last element as the
+    /// initial accumulator.
+    ///
+    /// If the list is empty, `None` is returned.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use std::sync::Arc;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   Some(Arc::new(-2)),
+    ///   list![1, 2, 3, 4].fold_right1(|a, b| *a - *b)
+    /// );
+    /// # }
+    /// ```
+    pub fn fold_right1<F, R>(&self, f: F) -> Option<Arc<A>>
+    where
+        F: Fn(Arc<A>, Arc<A>) -> R,
+        R: Shared<A>,
+    {
+        let items: Vec<Arc<A>> = self.iter().collect();
+        let mut it = items.into_iter().rev();
+        it.next().map(|last| it.fold(last, |acc, a| f(a, acc).shared()))
+    }
+
+    /// Reduce a list from the left, starting with an initial
+    /// accumulator value.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   15,
+    ///   list![1, 2, 3, 4, 5].fold(0, |acc, a| acc + a)
+    /// );
+    /// # }
+    /// ```
+    pub fn fold<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &A) -> B,
+    {
+        self.iter().fold(init, |acc, a| f(acc, &a))
+    }
+
+    /// Reduce a list from the right, starting with an initial
+    /// accumulator value.
+    ///
+    /// The elements are collected in a single forward pass and folded
+    /// over in reverse, so this doesn't recurse. Unlike a right fold
+    /// in a lazy language, it always visits every element.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   "123",
+    ///   list![1, 2, 3].fold_right(String::new(), |a, acc| format!("{}{}", a, acc))
+    /// );
+    /// # }
+    /// ```
+    pub fn fold_right<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(&A, B) -> B,
+    {
+        let items: Vec<Arc<A>> = self.iter().collect();
+        items.into_iter().rev().fold(init, |acc, a| f(&a, acc))
+    }
+
+    /// Call a function on each element of a list, in order.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// let mut out = Vec::new();
+    /// list![1, 2, 3].for_each(|a| out.push(*a));
+    /// assert_eq!(vec![1, 2, 3], out);
+    /// # }
+    /// ```
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(&A),
+    {
+        for a in self.iter() {
+            f(&a)
+        }
+    }
+
+    /// Map each element of a list to a monoid and combine the results.
+    ///
+    /// The monoid's identity is given by its [`Default`][Default]
+    /// implementation, and its combining operation by its
+    /// [`Add`][Add] implementation.
     ///
     /// Time: O(n)
     ///
@@ -428,101 +1731,54 @@ impl<A> List<A> {
     ///
     /// ```
     /// # #[macro_use] extern crate im;
-    /// # use im::list::List;
     /// # fn main() {
     /// assert_eq!(
-    ///   list![1, 2, 3, 4, 5].reverse(),
-    ///   list![5, 4, 3, 2, 1]
+    ///   12,
+    ///   list!["foo", "barbaz", "qu", "x"].fold_map(|s| s.len())
     /// );
     /// # }
     /// ```
-    pub fn reverse(&self) -> Self {
-        let mut out = List::new();
-        for i in self.iter() {
-            out = out.cons(i)
-        }
-        out
+    ///
+    /// [Default]: https://doc.rust-lang.org/std/default/trait.Default.html
+    /// [Add]: https://doc.rust-lang.org/std/ops/trait.Add.html
+    pub fn fold_map<M, F>(&self, f: F) -> M
+    where
+        F: Fn(Arc<A>) -> M,
+        M: Default + Add<Output = M>,
+    {
+        self.iter().fold(M::default(), |acc, a| acc + f(a))
     }
 
-    /// Sort a list using a comparator function.
+    /// Apply a fallible function to each element of a list, collecting
+    /// the results into a new list.
     ///
-    /// Time: O(n log n)
-    pub fn sort_by<F>(&self, cmp: F) -> Self
+    /// Stops at the first element for which the function returns an
+    /// [`Err`][Err], and returns that error.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   Ok(list![1, 2, 3]),
+    ///   list!["1", "2", "3"].traverse(|s| s.parse::<i32>())
+    /// );
+    ///
+    /// assert!(
+    ///   list!["1", "two", "3"].traverse(|s| s.parse::<i32>()).is_err()
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [Err]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn traverse<B, E, F>(&self, f: F) -> Result<List<B>, E>
     where
-        F: Fn(Arc<A>, Arc<A>) -> Ordering,
+        F: Fn(Arc<A>) -> Result<B, E>,
     {
-        fn merge<A>(la: &List<A>, lb: &List<A>, cmp: &Fn(Arc<A>, Arc<A>) -> Ordering) -> List<A> {
-            match (la.uncons(), lb.uncons()) {
-                (Some((ref a, _)), Some((ref b, ref lb1)))
-                    if cmp(a.clone(), b.clone()) == Ordering::Greater =>
-                {
-                    cons(b.clone(), &merge(la, lb1, cmp))
-                }
-                (Some((a, la1)), Some((_, _))) => cons(a.clone(), &merge(&la1, lb, cmp)),
-                (None, _) => lb.clone(),
-                (_, None) => la.clone(),
-            }
-        }
-
-        fn merge_pairs<A>(
-            l: &List<List<A>>,
-            cmp: &Fn(Arc<A>, Arc<A>) -> Ordering,
-        ) -> List<List<A>> {
-            match l.uncons2() {
-                Some((a, b, rest)) => cons(merge(&a, &b, cmp), &merge_pairs(&rest, cmp)),
-                _ => l.clone(),
-            }
-        }
-
-        fn merge_all<A>(l: &List<List<A>>, cmp: &Fn(Arc<A>, Arc<A>) -> Ordering) -> List<A> {
-            match l.uncons() {
-                None => list![],
-                Some((ref a, ref d)) if d.is_empty() => a.deref().clone(),
-                _ => merge_all(&merge_pairs(l, cmp), cmp),
-            }
-        }
-
-        fn ascending<A>(
-            a: &Arc<A>,
-            f: &Fn(List<A>) -> List<A>,
-            l: &List<A>,
-            cmp: &Fn(Arc<A>, Arc<A>) -> Ordering,
-        ) -> List<List<A>> {
-            match l.uncons() {
-                Some((ref b, ref lb)) if cmp(a.clone(), b.clone()) != Ordering::Greater => {
-                    ascending(&b.clone(), &|ys| f(cons(a.clone(), &ys)), lb, cmp)
-                }
-                _ => cons(f(List::singleton(a.clone())), &sequences(l, cmp)),
-            }
-        }
-
-        fn descending<A>(
-            a: &Arc<A>,
-            la: &List<A>,
-            lb: &List<A>,
-            cmp: &Fn(Arc<A>, Arc<A>) -> Ordering,
-        ) -> List<List<A>> {
-            match lb.uncons() {
-                Some((ref b, ref bs)) if cmp(a.clone(), b.clone()) == Ordering::Greater => {
-                    descending(&b.clone(), &cons(a.clone(), la), bs, cmp)
-                }
-                _ => cons(cons(a.clone(), la), &sequences(lb, cmp)),
-            }
-        }
-
-        fn sequences<A>(l: &List<A>, cmp: &Fn(Arc<A>, Arc<A>) -> Ordering) -> List<List<A>> {
-            match l.uncons2() {
-                Some((ref a, ref b, ref xs)) if cmp(a.clone(), b.clone()) == Ordering::Greater => {
-                    descending(&b.clone(), &List::singleton(a.clone()), xs, cmp)
-                }
-                Some((ref a, ref b, ref xs)) => {
-                    ascending(&b.clone(), &|l| cons(a.clone(), l), xs, cmp)
-                }
-                None => list![l.clone()],
-            }
-        }
-
-        merge_all(&sequences(self, &cmp), &cmp)
+        self.iter().map(f).collect()
     }
 }
 
@@ -573,12 +1829,103 @@ impl<A: Ord> List<A> {
         self.sort_by(|a, b| a.as_ref().cmp(b.as_ref()))
     }
 
+    /// Merge two sorted lists into a single sorted list.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # use im::list::List;
+    /// # fn main() {
+    /// let evens = List::range(0, 4).fold(List::new(), |l, a| l.snoc(a * 2));
+    /// let odds = List::range(0, 4).fold(List::new(), |l, a| l.snoc(a * 2 + 1));
+    /// assert_eq!(evens.merge(odds), List::range(0, 9));
+    /// # }
+    /// ```
+    pub fn merge<R>(&self, other: R) -> Self
+    where
+        R: Borrow<Self>,
+    {
+        self.merge_by(other, |a, b| a.as_ref().cmp(b.as_ref()))
+    }
+
+    /// Construct a list of the smallest element seen so far at each
+    /// position of the current list.
+    ///
+    /// Where several elements compare equal, the earliest one is
+    /// kept.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![3, 1, 4, 1, 5].running_min(),
+    ///   list![3, 1, 1, 1, 1]
+    /// );
+    /// # }
+    /// ```
+    pub fn running_min(&self) -> Self {
+        let mut min: Option<Arc<A>> = None;
+        self.iter()
+            .map(|a| {
+                let next = match min {
+                    Some(ref m) if m <= &a => m.clone(),
+                    _ => a,
+                };
+                min = Some(next.clone());
+                next
+            })
+            .collect()
+    }
+
+    /// Construct a list of the largest element seen so far at each
+    /// position of the current list.
+    ///
+    /// Where several elements compare equal, the earliest one is
+    /// kept.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![3, 1, 4, 1, 5].running_max(),
+    ///   list![3, 3, 4, 4, 5]
+    /// );
+    /// # }
+    /// ```
+    pub fn running_max(&self) -> Self {
+        let mut max: Option<Arc<A>> = None;
+        self.iter()
+            .map(|a| {
+                let next = match max {
+                    Some(ref m) if m >= &a => m.clone(),
+                    _ => a,
+                };
+                max = Some(next.clone());
+                next
+            })
+            .collect()
+    }
+
     /// Insert an item into a sorted list.
     ///
     /// Constructs a new list with the new item inserted before the
     /// first item in the list which is larger than the new item,
     /// as determined by the `Ord` trait.
     ///
+    /// Only the elements before the insertion point are copied; the
+    /// rest of the list is shared with the current list.
+    ///
     /// Time: O(n)
     ///
     /// # Examples
@@ -596,23 +1943,121 @@ impl<A: Ord> List<A> {
     where
         T: Shared<A>,
     {
-        self.insert_ref(item.shared())
+        self.insert_by(item, |a, b| a.as_ref().cmp(b.as_ref()))
     }
+}
 
-    fn insert_ref(&self, item: Arc<A>) -> Self {
-        match self.uncons() {
-            None => List::singleton(item),
-            Some((a, d)) => {
-                if a.deref() > item.deref() {
-                    self.cons(item)
-                } else {
-                    d.insert_ref(item).cons(a.clone())
+impl<A> List<List<A>> {
+    /// Turn a list of rows into a list of columns.
+    ///
+    /// If the rows have different lengths, every row is truncated to
+    /// the length of the shortest one.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![list![1, 2, 3], list![4, 5, 6]].transpose(),
+    ///   list![list![1, 4], list![2, 5], list![3, 6]]
+    /// );
+    /// # }
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let width = self.iter().map(|row| row.len()).min().unwrap_or(0);
+        let mut rows: Vec<Iter<A>> = self.iter().map(|row| row.iter()).collect();
+        (0..width)
+            .map(|_| rows.iter_mut().filter_map(|row| row.next()).collect::<List<A>>())
+            .collect()
+    }
+
+    /// Construct a list by taking one element from each list in turn
+    /// until all of them are exhausted.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![list![1, 4, 6], list![2], list![3, 5]].interleave_all(),
+    ///   list![1, 2, 3, 4, 5, 6]
+    /// );
+    /// # }
+    /// ```
+    pub fn interleave_all(&self) -> List<A> {
+        let mut rows: Vec<Iter<A>> = self.iter().map(|row| row.iter()).collect();
+        let mut out = List::new();
+        loop {
+            let mut exhausted = true;
+            for row in &mut rows {
+                if let Some(a) = row.next() {
+                    out = out.snoc(a);
+                    exhausted = false;
                 }
             }
+            if exhausted {
+                return out;
+            }
         }
     }
 }
 
+impl<A: Clone, B: Clone> List<(A, B)> {
+    /// Split a list of pairs into a list of their first elements and
+    /// a list of their second elements.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![(1, "a"), (2, "b"), (3, "c")].unzip(),
+    ///   (list![1, 2, 3], list!["a", "b", "c"])
+    /// );
+    /// # }
+    /// ```
+    pub fn unzip(&self) -> (List<A>, List<B>) {
+        self.iter().fold((List::new(), List::new()), |(left, right), pair| {
+            (left.snoc(pair.0.clone()), right.snoc(pair.1.clone()))
+        })
+    }
+}
+
+impl<A: Eq + Hash> List<A> {
+    /// Remove all duplicate elements from a list.
+    ///
+    /// Unlike a deduplication of adjacent elements, this removes
+    /// every repeated occurrence of a value anywhere in the list,
+    /// keeping only the first.
+    ///
+    /// Time: O(n)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate im;
+    /// # fn main() {
+    /// assert_eq!(
+    ///   list![1, 2, 1, 3, 2].nub(),
+    ///   list![1, 2, 3]
+    /// );
+    /// # }
+    /// ```
+    pub fn nub(&self) -> Self {
+        let mut seen = HashSet::new();
+        self.iter().filter(|a| seen.insert(a.clone())).collect()
+    }
+}
+
 fn fold_queue<A, F>(f: F, seed: List<A>, queue: &Queue<List<A>>) -> List<A>
 where
     F: Fn(List<A>, List<A>) -> List<A>,
@@ -632,6 +2077,54 @@ impl<A> Clone for List<A> {
     }
 }
 
+impl<A> Drop for List<A> {
+    /// Drop a list.
+    ///
+    /// Sublists which aren't shared with any other list and have
+    /// sublists of their own are detached and dropped one at a time,
+    /// so that dropping a deeply nested list, such as one built by
+    /// consing or what's left of a long list after iterating over
+    /// part of it, doesn't recurse once for every level of nesting.
+    /// Detached sublists are all replaced with the same empty list,
+    /// so this doesn't allocate per sublist.
+    fn drop(&mut self) {
+        let mut empty = None;
+        let mut detached = Vec::new();
+        detach_sublists(&mut self.0, &mut empty, &mut detached);
+        while let Some(mut node) = detached.pop() {
+            detach_sublists(&mut node, &mut empty, &mut detached);
+        }
+    }
+}
+
+fn detach_sublists<A>(
+    node: &mut Arc<ListNode<A>>,
+    empty: &mut Option<Arc<ListNode<A>>>,
+    detached: &mut Vec<Arc<ListNode<A>>>,
+) {
+    if !is_nested(node) {
+        return;
+    }
+    if let Some(&mut Cons(_, _, ref mut queue)) = Arc::get_mut(node) {
+        queue.for_each_unshared(|sublist| {
+            if Arc::strong_count(sublist) > 1 || !is_nested(&sublist.0) {
+                return;
+            }
+            if let Some(sublist) = Arc::get_mut(sublist) {
+                let empty = empty.get_or_insert_with(|| Arc::new(Nil)).clone();
+                detached.push(mem::replace(&mut sublist.0, empty));
+            }
+        });
+    }
+}
+
+fn is_nested<A>(node: &Arc<ListNode<A>>) -> bool {
+    Arc::strong_count(node) == 1 && match **node {
+        Cons(_, _, ref queue) => !queue.is_empty(),
+        Nil => false,
+    }
+}
+
 impl<A> Default for List<A> {
     fn default() -> Self {
         List::new()
@@ -891,6 +2384,7 @@ mod test {
     use super::*;
     use super::proptest::*;
     use test::is_sorted;
+    use iter::unfold;
     use proptest::num::i32;
     use proptest::collection;
 
@@ -936,6 +2430,129 @@ mod test {
             let sorted = l.sort();
             l.len() == sorted.len() && is_sorted(&sorted)
         }
+
+        fn rotate_round_trip(l: List<i32>, n: usize) -> bool {
+            let rotated = l.rotate_left(n);
+            rotated.len() == l.len() && rotated.rotate_right(n) == l
+        }
+
+        fn pad_to_shares_fill(l: List<i32>, len: usize) -> bool {
+            let len = len % 100;
+            let fill = Arc::new(0);
+            let padded = l.pad_to(len, fill.clone());
+            padded.len() == ::std::cmp::max(len, l.len())
+                && padded.iter().skip(l.len()).all(|a| Arc::ptr_eq(&a, &fill))
+        }
+
+        fn take_and_drop(l: List<i32>, n: usize) -> bool {
+            let n = n % (l.len() + 2);
+            l.take(n).append(l.drop(n)) == l
+        }
+
+        fn compare_bounded_agrees(xs: List<i32>, ys: List<i32>) -> bool {
+            let limit = ::std::cmp::max(xs.len(), ys.len());
+            xs.compare_bounded(&ys, limit) == xs.partial_cmp(&ys)
+        }
+
+        fn bounded_comparisons_agree(xs: List<i32>, ys: List<i32>, limit: usize) -> bool {
+            let limit = limit % (xs.len() + ys.len() + 2);
+            let agree = |xs: &List<i32>, ys: &List<i32>| {
+                let ord = xs.compare_bounded(ys, limit).unwrap_or(Ordering::Equal);
+                xs.eq_bounded(ys, limit) == (ord == Ordering::Equal)
+            };
+            agree(&xs, &ys) && agree(&xs, &xs.append(&ys)) && agree(&xs.append(&ys), &xs)
+        }
+
+        fn compare_bounded_within_limit(xs: List<i32>, ys: List<i32>, limit: usize) -> bool {
+            let limit = limit % (::std::cmp::max(xs.len(), ys.len()) + 2);
+            let prefix = |l: &List<i32>| l.iter().take(limit).map(|a| *a).collect::<Vec<_>>();
+            let (a, b) = (prefix(&xs), prefix(&ys));
+            let expected = if a != b {
+                Some(a.cmp(&b))
+            } else if xs.len() <= limit && ys.len() <= limit {
+                Some(Ordering::Equal)
+            } else {
+                None
+            };
+            xs.compare_bounded(&ys, limit) == expected
+        }
+
+        fn take_while_and_drop_while(l: List<i32>) -> bool {
+            let small = |a: &i32| *a < 0;
+            l.take_while(small).append(l.drop_while(small)) == l
+        }
+
+        fn insert_into_sorted_list(l: List<i32>, a: i32) -> bool {
+            let sorted = l.sort();
+            let inserted = sorted.insert(a);
+            inserted.len() == l.len() + 1 && is_sorted(&inserted)
+        }
+
+        fn last_of_tail(l: List<i32>) -> bool {
+            let rest = l.tail().unwrap_or_default();
+            let last = rest.iter().map(|a| *a).last();
+            rest.last().map(|a| *a) == last && rest.pop_back().map(|(a, _)| *a) == last
+        }
+
+        fn get_by_index(vec: Vec<i32>, index: usize) -> bool {
+            let list = List::from_iter(vec.clone());
+            list.get(index).map(|a| *a) == vec.get(index).cloned()
+        }
+
+        fn cycle_n_repeats(l: List<i32>, times: usize) -> bool {
+            let times = times % 10;
+            let cycled = l.cycle_n(times);
+            cycled.len() == l.len() * times && cycled.iter().eq((0..times).flat_map(|_| l.iter()))
+        }
+
+        fn sort_by_is_stable(vec: Vec<(u8, i32)>) -> bool {
+            let list = List::from_iter(vec.clone());
+            let mut sorted = vec;
+            sorted.sort_by_key(|a| a.0);
+            list.sort_by(|a, b| a.0.cmp(&b.0)) == List::from_iter(sorted)
+        }
+
+        fn merge_sorted_lists(xs: List<i32>, ys: List<i32>) -> bool {
+            let merged = xs.sort().merge(ys.sort());
+            merged.len() == xs.len() + ys.len() && is_sorted(&merged)
+                && merged == xs.append(&ys).sort()
+        }
+
+        fn eq_bounded_agrees(xs: List<i32>, ys: List<i32>) -> bool {
+            let limit = ::std::cmp::max(xs.len(), ys.len());
+            xs.eq_bounded(&ys, limit) == (xs == ys)
+        }
+
+        fn dedup_by_agrees_with_vec(l: List<i32>) -> bool {
+            let mut v: Vec<i32> = l.iter().map(|a| *a).collect();
+            v.dedup_by(|a, b| *a > *b);
+            l.dedup_by(|a, b| a > b) == List::from(v)
+        }
+
+        fn dedup_agrees_with_vec(l: List<i32>) -> bool {
+            let mut v: Vec<i32> = l.iter().map(|a| *a).collect();
+            v.dedup();
+            l.dedup() == List::from(v)
+        }
+
+        fn take_drop_agrees(l: List<i32>, n: usize) -> bool {
+            let n = n % (l.len() + 2);
+            l.take_drop(n) == (l.take(n), l.drop(n))
+        }
+
+        fn running_min_and_max(l: List<i32>) -> bool {
+            let (mins, maxes) = (l.running_min(), l.running_max());
+            mins.len() == l.len() && maxes.len() == l.len()
+                && (1..l.len() + 1).all(|n| {
+                    let prefix = l.take(n);
+                    mins.get(n - 1) == prefix.iter().min()
+                        && maxes.get(n - 1) == prefix.iter().max()
+                })
+        }
+
+        fn is_sorted_agrees(l: List<i32>) -> bool {
+            l.is_sorted() == is_sorted(&l) && l.sort_by_key(|a| -a).reverse().is_sorted()
+        }
     }
 
     proptest! {
@@ -958,4 +2575,84 @@ mod test {
                        list.pop_back());
         }
     }
+
+    #[test]
+    fn fold_large_list() {
+        let list = List::from(unfold(0u64, |i| Some((*i, *i + 1))).take(1_000_000));
+        assert_eq!(499_999_500_000, list.fold(0, |acc, a| acc + a));
+    }
+
+    #[test]
+    fn reverse_large_list() {
+        let list = List::from(unfold(0u64, |i| Some((*i, *i + 1))).take(1_000_000));
+        let reversed = list.reverse();
+        assert_eq!(Some(Arc::new(999_999)), reversed.head());
+        assert_eq!(Some(Arc::new(0)), reversed.last());
+    }
+
+    #[test]
+    fn insert_into_middle() {
+        assert_eq!(list![1, 2, 3, 4, 5], list![1, 2, 4, 5].insert(3));
+    }
+
+    #[test]
+    fn insert_shares_suffix() {
+        let suffix = list![4, 5];
+        let list = list![1, 2].append(&suffix);
+        let inserted = list.insert(3);
+        assert_eq!(list![1, 2, 3, 4, 5], inserted);
+        assert!(Arc::ptr_eq(&inserted.drop(3).0, &suffix.0));
+    }
+
+    #[test]
+    fn drop_nested_list() {
+        let list = List::range(0, 1_000_000);
+        assert_eq!(1_000_001, list.len());
+        drop(list);
+    }
+
+    #[test]
+    fn eq_bounded_naturals() {
+        let naturals = |len| List::from(unfold(0u64, |i| Some((*i, *i + 1))).take(len));
+        assert!(naturals(100_000).eq_bounded(naturals(5000), 1000));
+        assert!(!naturals(100_000).eq_bounded(naturals(5000), 10_000));
+    }
+
+    #[test]
+    fn fold_right1_nested_list() {
+        let list = List::range(0, 100_000);
+        assert_eq!(Some(Arc::new(0)), list.fold_right1(|a, _| *a));
+    }
+
+    #[test]
+    fn is_sorted_rejects_nan() {
+        assert!(!List::from(vec![1.0, f64::NAN, 0.5]).is_sorted());
+        assert!(!List::from(vec![f64::NAN, f64::NAN]).is_sorted());
+        assert!(List::from(vec![f64::NAN]).is_sorted());
+    }
+
+    #[test]
+    fn fold_right_nested_list() {
+        let list = List::range(0, 100_000);
+        let visited = list.fold_right(Vec::new(), |a, mut acc| {
+            acc.push(*a);
+            acc
+        });
+        assert_eq!((0..100_001).rev().collect::<Vec<_>>(), visited);
+    }
+
+    #[test]
+    fn sort_large_list() {
+        let list = List::range(0, 200_000);
+        assert_eq!(list, list.sort());
+        assert_eq!(list, list.reverse().sort());
+        assert_eq!(list.reverse(), list.sort_by_key(|a| -a));
+    }
+
+    #[test]
+    fn dedup_by_argument_order() {
+        let list = list![1, 2, 4, 5, 9, 10];
+        assert_eq!(list![1, 4, 9], list.dedup_by(|current, kept| current - kept == 1));
+        assert_eq!(list, list.dedup_by(|current, kept| kept - current == 1));
+    }
 }
@@ -140,6 +140,18 @@ impl<A> Queue<A> {
     pub fn ptr_eq(&self, other: &Self) -> bool {
         self.0.ptr_eq(&other.0) && self.1.ptr_eq(&other.1)
     }
+
+    /// Call a function with each element of the queue whose cell
+    /// isn't shared with any other queue, in no particular order.
+    ///
+    /// Time: O(n) in the number of unshared cells
+    pub(crate) fn for_each_unshared<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Arc<A>),
+    {
+        self.0.for_each_unshared(&mut f);
+        self.1.for_each_unshared(f);
+    }
 }
 
 // Core traits